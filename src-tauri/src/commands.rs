@@ -0,0 +1,103 @@
+use tauri::State;
+
+use crate::sidecar::SidecarManager;
+use crate::state::SharedState;
+use crate::types::{ConnectionStatus, NowPlayingData, SidecarCommand, ZoneInfo};
+
+/// Snapshot of the now-playing surface rendered inside the tray popover.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct NowPlayingSnapshot {
+    pub track: Option<NowPlayingData>,
+    pub active_zone_id: Option<String>,
+    pub connected: bool,
+}
+
+/// Fetch the current track plus enough context for the popover to render
+/// artwork, title/artist and the transport state.
+#[tauri::command]
+pub async fn get_now_playing(state: State<'_, SharedState>) -> Result<NowPlayingSnapshot, String> {
+    let state_guard = state.read().await;
+
+    Ok(NowPlayingSnapshot {
+        track: state_guard.current_track.clone(),
+        active_zone_id: state_guard.active_zone_id.clone(),
+        connected: matches!(state_guard.connection_status, ConnectionStatus::Connected),
+    })
+}
+
+/// Return every discovered zone so the popover can offer a quick zone picker.
+#[tauri::command]
+pub async fn list_zones(state: State<'_, SharedState>) -> Result<Vec<ZoneInfo>, String> {
+    let state_guard = state.read().await;
+
+    let zones = state_guard.all_zones.iter().map(ZoneInfo::from).collect();
+
+    Ok(zones)
+}
+
+/// Resolve the target zone for a popover control: the explicit `zone_id` if
+/// given, otherwise the current active zone.
+async fn resolve_zone(state: &SharedState, zone_id: Option<String>) -> Result<String, String> {
+    if let Some(zone_id) = zone_id {
+        return Ok(zone_id);
+    }
+
+    let state_guard = state.read().await;
+    state_guard
+        .active_zone_id
+        .clone()
+        .ok_or_else(|| "no active zone".to_string())
+}
+
+/// Toggle play/pause for the popover's zone (the active zone if unspecified).
+#[tauri::command]
+pub async fn transport_play_pause(
+    zone_id: Option<String>,
+    state: State<'_, SharedState>,
+    sidecar: State<'_, SidecarManager>,
+) -> Result<(), String> {
+    let zone_id = resolve_zone(&state, zone_id).await?;
+    sidecar
+        .send_command(SidecarCommand::PlayPause { zone_id })
+        .map_err(|e| e.to_string())
+}
+
+/// Skip to the next track for the popover's zone.
+#[tauri::command]
+pub async fn transport_next(
+    zone_id: Option<String>,
+    state: State<'_, SharedState>,
+    sidecar: State<'_, SidecarManager>,
+) -> Result<(), String> {
+    let zone_id = resolve_zone(&state, zone_id).await?;
+    sidecar
+        .send_command(SidecarCommand::Next { zone_id })
+        .map_err(|e| e.to_string())
+}
+
+/// Skip to the previous track for the popover's zone.
+#[tauri::command]
+pub async fn transport_previous(
+    zone_id: Option<String>,
+    state: State<'_, SharedState>,
+    sidecar: State<'_, SidecarManager>,
+) -> Result<(), String> {
+    let zone_id = resolve_zone(&state, zone_id).await?;
+    sidecar
+        .send_command(SidecarCommand::Previous { zone_id })
+        .map_err(|e| e.to_string())
+}
+
+/// Seek to `seconds` within the current track (backs the popover scrubber).
+#[tauri::command]
+pub async fn seek(
+    zone_id: Option<String>,
+    seconds: u32,
+    state: State<'_, SharedState>,
+    sidecar: State<'_, SidecarManager>,
+) -> Result<(), String> {
+    let zone_id = resolve_zone(&state, zone_id).await?;
+    sidecar
+        .send_command(SidecarCommand::Seek { zone_id, seconds })
+        .map_err(|e| e.to_string())
+}
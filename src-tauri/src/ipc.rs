@@ -0,0 +1,260 @@
+//! Local IPC server exposing now-playing and zone state to external tools.
+//!
+//! Listens on a Unix domain socket (a named pipe on Windows) and speaks
+//! newline-delimited JSON. Requests cover read queries (`GetNowPlaying`,
+//! `ListZones`), `SetZonePreference`, and transport verbs that forward to
+//! [`SidecarManager::send_command`]. Every reply is a tagged envelope:
+//!
+//! - `{ "type": "success", "content": … }` — the result payload
+//! - `{ "type": "failure", "content": msg }` — a recoverable error
+//! - `{ "type": "fatal", "content": msg }` — an unrecoverable condition
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::sidecar::SidecarManager;
+use crate::state::SharedState;
+use crate::types::{NowPlayingData, SidecarCommand, ZoneInfo, ZonePreference};
+
+/// A request from an external client.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IpcRequest {
+    GetNowPlaying,
+    ListZones,
+    SetZonePreference { preference: ZonePreference },
+    PlayPause { zone_id: String },
+    Next { zone_id: String },
+    Previous { zone_id: String },
+    Stop { zone_id: String },
+    Seek { zone_id: String, seconds: u32 },
+}
+
+/// The tagged response envelope modelled on the `Response<A>` pattern.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", content = "content", rename_all = "snake_case")]
+pub enum Response {
+    Success(serde_json::Value),
+    Failure(String),
+    Fatal(String),
+}
+
+impl Response {
+    fn success(value: impl Serialize) -> Self {
+        match serde_json::to_value(value) {
+            Ok(value) => Response::Success(value),
+            Err(e) => Response::Fatal(format!("failed to serialize response: {}", e)),
+        }
+    }
+}
+
+/// Handle a single request against the current state.
+async fn handle_request<R: Runtime>(
+    request: IpcRequest,
+    app: &AppHandle<R>,
+    state: &SharedState,
+) -> Response {
+    match request {
+        IpcRequest::GetNowPlaying => {
+            let state_guard = state.read().await;
+            match &state_guard.current_track {
+                Some(track) => Response::success(track.clone()),
+                None => Response::success(Option::<NowPlayingData>::None),
+            }
+        }
+        IpcRequest::ListZones => {
+            let state_guard = state.read().await;
+            let zones: Vec<ZoneInfo> = state_guard.all_zones.iter().map(ZoneInfo::from).collect();
+            Response::success(zones)
+        }
+        IpcRequest::SetZonePreference { preference } => {
+            // Reject selection of an unknown zone.
+            if let ZonePreference::Selected { zone_id, .. } = &preference {
+                let state_guard = state.read().await;
+                if !state_guard.all_zones.iter().any(|z| &z.zone_id == zone_id) {
+                    return Response::Failure(format!("unknown zone id: {}", zone_id));
+                }
+            }
+            let mut state_guard = state.write().await;
+            state_guard.zone_preference = preference;
+            Response::success(serde_json::json!({ "ok": true }))
+        }
+        IpcRequest::PlayPause { zone_id } => {
+            forward_command(app, SidecarCommand::PlayPause { zone_id })
+        }
+        IpcRequest::Next { zone_id } => forward_command(app, SidecarCommand::Next { zone_id }),
+        IpcRequest::Previous { zone_id } => {
+            forward_command(app, SidecarCommand::Previous { zone_id })
+        }
+        IpcRequest::Stop { zone_id } => forward_command(app, SidecarCommand::Stop { zone_id }),
+        IpcRequest::Seek { zone_id, seconds } => {
+            forward_command(app, SidecarCommand::Seek { zone_id, seconds })
+        }
+    }
+}
+
+/// Forward a transport command to the sidecar, mapping errors to the envelope.
+fn forward_command<R: Runtime>(app: &AppHandle<R>, cmd: SidecarCommand) -> Response {
+    let Some(sidecar) = app.try_state::<SidecarManager>() else {
+        return Response::Fatal("sidecar manager unavailable".to_string());
+    };
+
+    match sidecar.send_command(cmd) {
+        Ok(()) => Response::success(serde_json::json!({ "ok": true })),
+        // A write failure here means the sidecar is temporarily gone; callers
+        // can retry, so this is recoverable.
+        Err(e) => Response::Failure(format!("sidecar disconnected: {}", e)),
+    }
+}
+
+/// Serialize a response into a single newline-terminated JSON line.
+fn encode_response(response: &Response) -> String {
+    match serde_json::to_string(response) {
+        Ok(line) => format!("{}\n", line),
+        Err(e) => format!("{{\"type\":\"fatal\",\"content\":\"encode error: {}\"}}\n", e),
+    }
+}
+
+#[cfg(unix)]
+pub fn start<R: Runtime>(app: AppHandle<R>, state: SharedState) {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::UnixListener;
+
+    let socket_path = std::env::temp_dir().join("macaroon.sock");
+
+    tauri::async_runtime::spawn(async move {
+        // Clear any stale socket from a previous run.
+        let _ = tokio::fs::remove_file(&socket_path).await;
+
+        let listener = match UnixListener::bind(&socket_path) {
+            Ok(listener) => listener,
+            Err(e) => {
+                log::warn!("Failed to bind IPC socket at {:?}: {}", socket_path, e);
+                return;
+            }
+        };
+        log::info!("IPC server listening on {:?}", socket_path);
+
+        loop {
+            let (stream, _addr) = match listener.accept().await {
+                Ok(pair) => pair,
+                Err(e) => {
+                    log::error!("IPC accept failed: {}", e);
+                    continue;
+                }
+            };
+
+            let app = app.clone();
+            let state = state.clone();
+            tauri::async_runtime::spawn(async move {
+                let (read_half, mut write_half) = stream.into_split();
+                let mut lines = BufReader::new(read_half).lines();
+
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+
+                    let response = match serde_json::from_str::<IpcRequest>(&line) {
+                        Ok(request) => handle_request(request, &app, &state).await,
+                        Err(e) => Response::Failure(format!("invalid request: {}", e)),
+                    };
+
+                    if write_half
+                        .write_all(encode_response(&response).as_bytes())
+                        .await
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+}
+
+#[cfg(windows)]
+pub fn start<R: Runtime>(app: AppHandle<R>, state: SharedState) {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::windows::named_pipe::ServerOptions;
+
+    const PIPE_NAME: &str = r"\\.\pipe\macaroon";
+
+    tauri::async_runtime::spawn(async move {
+        log::info!("IPC server listening on named pipe {}", PIPE_NAME);
+
+        loop {
+            let server = match ServerOptions::new().create(PIPE_NAME) {
+                Ok(server) => server,
+                Err(e) => {
+                    log::error!("Failed to create named pipe: {}", e);
+                    return;
+                }
+            };
+
+            if let Err(e) = server.connect().await {
+                log::error!("Named pipe connect failed: {}", e);
+                continue;
+            }
+
+            let app = app.clone();
+            let state = state.clone();
+            tauri::async_runtime::spawn(async move {
+                let (read_half, mut write_half) = tokio::io::split(server);
+                let mut lines = BufReader::new(read_half).lines();
+
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+
+                    let response = match serde_json::from_str::<IpcRequest>(&line) {
+                        Ok(request) => handle_request(request, &app, &state).await,
+                        Err(e) => Response::Failure(format!("invalid request: {}", e)),
+                    };
+
+                    if write_half
+                        .write_all(encode_response(&response).as_bytes())
+                        .await
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn success_envelope_has_tag_and_content() {
+        let response = Response::success(serde_json::json!({ "ok": true }));
+        let json = serde_json::to_string(&response).unwrap();
+        assert_eq!(json, r#"{"type":"success","content":{"ok":true}}"#);
+    }
+
+    #[test]
+    fn failure_envelope_carries_message() {
+        let response = Response::Failure("unknown zone id: z1".to_string());
+        let json = serde_json::to_string(&response).unwrap();
+        assert_eq!(json, r#"{"type":"failure","content":"unknown zone id: z1"}"#);
+    }
+
+    #[test]
+    fn fatal_envelope_carries_message() {
+        let response = Response::Fatal("sidecar gone".to_string());
+        let json = serde_json::to_string(&response).unwrap();
+        assert_eq!(json, r#"{"type":"fatal","content":"sidecar gone"}"#);
+    }
+
+    #[test]
+    fn encode_response_is_newline_terminated() {
+        let line = encode_response(&Response::Failure("x".to_string()));
+        assert!(line.ends_with('\n'));
+        assert!(!line.trim_end().contains('\n'));
+    }
+}
@@ -1,13 +1,22 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod commands;
 mod compositor;
+mod ipc;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod mpris;
+mod providers;
 mod sidecar;
 mod state;
 mod tray;
+mod tray_state;
 mod types;
 
 use tauri::Manager;
+use tauri_plugin_global_shortcut::{Modifiers, Shortcut, ShortcutState};
+use tray_state::{TrayEvent, TrayStateMachine};
 
 fn main() {
     // Initialize logger
@@ -17,6 +26,55 @@ fn main() {
     log::info!("Starting Now Playing menu bar app");
 
     tauri::Builder::default()
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(|app, shortcut, event| {
+                    // Only act on key-press, not release.
+                    if event.state() != ShortcutState::Pressed {
+                        return;
+                    }
+
+                    // Map the OS media key to a tray event and feed it through
+                    // the same state machine that handles menu input.
+                    let tray_event = match shortcut.key {
+                        tauri_plugin_global_shortcut::Code::MediaPlayPause => {
+                            Some(TrayEvent::HotkeyPlayPause)
+                        }
+                        tauri_plugin_global_shortcut::Code::MediaTrackNext => {
+                            Some(TrayEvent::HotkeyNext)
+                        }
+                        tauri_plugin_global_shortcut::Code::MediaTrackPrevious => {
+                            Some(TrayEvent::HotkeyPrevious)
+                        }
+                        // Ctrl+Alt+Z cycles the active zone without opening the
+                        // menu. There is no dedicated media key for this, so a
+                        // modified letter key carries it.
+                        tauri_plugin_global_shortcut::Code::KeyZ
+                            if shortcut
+                                .mods
+                                .contains(Modifiers::CONTROL | Modifiers::ALT) =>
+                        {
+                            Some(TrayEvent::ZoneCycle)
+                        }
+                        _ => None,
+                    };
+
+                    if let (Some(tray_event), Some(state)) =
+                        (tray_event, app.try_state::<state::SharedState>())
+                    {
+                        TrayStateMachine::handle(app, &state, tray_event);
+                    }
+                })
+                .build(),
+        )
+        .invoke_handler(tauri::generate_handler![
+            commands::get_now_playing,
+            commands::list_zones,
+            commands::transport_play_pause,
+            commands::transport_next,
+            commands::transport_previous,
+            commands::seek,
+        ])
         .setup(|app| {
             log::info!("Setting up application");
 
@@ -55,6 +113,41 @@ fn main() {
             // Store sidecar manager in app state for cleanup
             app.manage(sidecar_manager);
 
+            // Register additional media providers alongside the Roon sidecar so
+            // their zones merge into `all_zones`. The sidecar remains the Roon
+            // backend; providers here contribute extra (prefixed) zones.
+            let mut registry = providers::ProviderRegistry::new();
+            registry.register(std::sync::Arc::new(providers::PlatformMediaProvider::new()));
+            registry.start_all(state.clone());
+            app.manage(registry);
+
+            // Register global media-key shortcuts so play/pause, next and
+            // previous work without opening the menu. All of them funnel
+            // through the tray state machine in the plugin handler above.
+            use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt};
+            let media_shortcuts = [
+                Shortcut::new(None, Code::MediaPlayPause),
+                Shortcut::new(None, Code::MediaTrackNext),
+                Shortcut::new(None, Code::MediaTrackPrevious),
+                // Zone cycling (see the plugin handler above).
+                Shortcut::new(Some(Modifiers::CONTROL | Modifiers::ALT), Code::KeyZ),
+            ];
+            if let Err(e) = app.global_shortcut().register_multiple(media_shortcuts) {
+                log::warn!("Failed to register global media shortcuts: {}", e);
+            }
+
+            // Expose an MPRIS MediaPlayer2 interface on Linux so desktop
+            // environments can show and control the active zone.
+            #[cfg(target_os = "linux")]
+            tauri::async_runtime::spawn(mpris::start(app.handle().clone(), state.clone()));
+
+            // Start the optional Prometheus exporter.
+            #[cfg(feature = "metrics")]
+            metrics::start(state.clone());
+
+            // Start the local IPC server for external integrations.
+            ipc::start(app.handle().clone(), state.clone());
+
             Ok(())
         })
         .build(tauri::generate_context!())
@@ -0,0 +1,201 @@
+//! Optional Prometheus telemetry, gated behind the `metrics` cargo feature.
+//!
+//! Reflects runtime state as gauges (discovered zones, per-state zone counts,
+//! connection status) and counters (sidecar restarts, JSON parse failures,
+//! handled messages by variant). Two delivery modes are supported: a local
+//! `/metrics` scrape endpoint and an interval push to a Prometheus Pushgateway.
+
+#![cfg(feature = "metrics")]
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use prometheus::{
+    register_int_counter, register_int_counter_vec, register_int_gauge, register_int_gauge_vec,
+    Encoder, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, TextEncoder,
+};
+
+use crate::state::SharedState;
+use crate::types::{ConnectionStatus, PlaybackState, SidecarMessage};
+
+static ZONES_TOTAL: Lazy<IntGauge> =
+    Lazy::new(|| register_int_gauge!("macaroon_zones_total", "Number of discovered zones").unwrap());
+
+static ZONE_STATE: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "macaroon_zone_state",
+        "Number of zones in each playback state",
+        &["state"]
+    )
+    .unwrap()
+});
+
+static CONNECTION_STATUS: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "macaroon_connection_status",
+        "Current sidecar connection status (1 for the active status)",
+        &["status"]
+    )
+    .unwrap()
+});
+
+static SIDECAR_RESTARTS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!("macaroon_sidecar_restarts_total", "Total sidecar restarts").unwrap()
+});
+
+static PARSE_FAILURES: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "macaroon_parse_failures_total",
+        "Total sidecar message JSON parse failures"
+    )
+    .unwrap()
+});
+
+static MESSAGES_HANDLED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "macaroon_messages_handled_total",
+        "Sidecar messages handled, by variant",
+        &["type"]
+    )
+    .unwrap()
+});
+
+/// Increment the JSON parse-failure counter (called from `read_stdout`).
+pub fn record_parse_failure() {
+    PARSE_FAILURES.inc();
+}
+
+/// Increment the sidecar restart counter (called from the supervisor).
+pub fn record_restart() {
+    SIDECAR_RESTARTS.inc();
+}
+
+/// Count a handled message by its variant name.
+pub fn record_message(message: &SidecarMessage) {
+    let variant = match message {
+        SidecarMessage::NowPlaying { .. } => "now_playing",
+        SidecarMessage::ZoneList { .. } => "zone_list",
+        SidecarMessage::Status { .. } => "status",
+        SidecarMessage::Error { .. } => "error",
+        SidecarMessage::Fatal { .. } => "fatal",
+    };
+    MESSAGES_HANDLED.with_label_values(&[variant]).inc();
+}
+
+/// Refresh the state-derived gauges from the shared state.
+fn refresh_from_state(state: &SharedState) {
+    let state_guard = state.blocking_read();
+
+    ZONES_TOTAL.set(state_guard.all_zones.len() as i64);
+
+    let mut counts = [0i64; 4]; // playing, paused, stopped, loading
+    for zone in &state_guard.all_zones {
+        match zone.state {
+            PlaybackState::Playing => counts[0] += 1,
+            PlaybackState::Paused => counts[1] += 1,
+            PlaybackState::Stopped => counts[2] += 1,
+            PlaybackState::Loading => counts[3] += 1,
+        }
+    }
+    ZONE_STATE.with_label_values(&["playing"]).set(counts[0]);
+    ZONE_STATE.with_label_values(&["paused"]).set(counts[1]);
+    ZONE_STATE.with_label_values(&["stopped"]).set(counts[2]);
+    ZONE_STATE.with_label_values(&["loading"]).set(counts[3]);
+
+    let active = match &state_guard.connection_status {
+        ConnectionStatus::Disconnected => "disconnected",
+        ConnectionStatus::Discovering => "discovering",
+        ConnectionStatus::Connected => "connected",
+        ConnectionStatus::Error(_) => "error",
+    };
+    for status in ["disconnected", "discovering", "connected", "error"] {
+        CONNECTION_STATUS
+            .with_label_values(&[status])
+            .set(if status == active { 1 } else { 0 });
+    }
+}
+
+/// Encode all metrics into the Prometheus text exposition format.
+fn encode() -> Vec<u8> {
+    let mut buffer = Vec::new();
+    let encoder = TextEncoder::new();
+    let metric_families = prometheus::gather();
+    if let Err(e) = encoder.encode(&metric_families, &mut buffer) {
+        log::error!("Failed to encode metrics: {}", e);
+    }
+    buffer
+}
+
+/// Start the exporter.
+///
+/// Reads `METRICS_PORT` (default 9185) for the local scrape endpoint and, when
+/// set, `METRICS_PUSHGATEWAY` for the interval push target.
+pub fn start(state: SharedState) {
+    let port: u16 = std::env::var("METRICS_PORT")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(9185);
+
+    // Local /metrics scrape endpoint.
+    {
+        let state = state.clone();
+        std::thread::spawn(move || serve_scrape(port, state));
+    }
+
+    // Optional push to a Pushgateway.
+    if let Ok(url) = std::env::var("METRICS_PUSHGATEWAY") {
+        let state = state.clone();
+        std::thread::spawn(move || push_loop(url, state));
+    }
+}
+
+fn serve_scrape(port: u16, state: SharedState) {
+    let listener = match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            log::error!("Failed to bind metrics endpoint on port {}: {}", port, e);
+            return;
+        }
+    };
+    log::info!("Metrics endpoint listening on http://127.0.0.1:{}/metrics", port);
+
+    for stream in listener.incoming() {
+        let Ok(mut stream) = stream else { continue };
+
+        // Drain the request line; we serve /metrics regardless of path.
+        let mut buf = [0u8; 1024];
+        let _ = stream.read(&mut buf);
+
+        refresh_from_state(&state);
+        let body = encode();
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\n\r\n",
+            body.len()
+        );
+        if stream.write_all(response.as_bytes()).is_ok() {
+            let _ = stream.write_all(&body);
+        }
+    }
+}
+
+fn push_loop(url: String, state: SharedState) {
+    log::info!("Pushing metrics to Pushgateway at {}", url);
+    loop {
+        std::thread::sleep(Duration::from_secs(15));
+        refresh_from_state(&state);
+
+        let metric_families = prometheus::gather();
+        if let Err(e) = prometheus::push_metrics(
+            "macaroon",
+            prometheus::labels! {},
+            &url,
+            metric_families,
+            None,
+        ) {
+            log::warn!("Failed to push metrics to {}: {}", url, e);
+        }
+    }
+}
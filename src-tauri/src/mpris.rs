@@ -0,0 +1,278 @@
+//! MPRIS `org.mpris.MediaPlayer2` integration for Linux desktops.
+//!
+//! Registers the standard MediaPlayer2 and MediaPlayer2.Player interfaces on
+//! the session bus, mirroring the currently active zone. Method calls from the
+//! desktop (media keys, panel applets) are translated into [`SidecarCommand`]s
+//! for `active_zone_id`, and [`notify_changed`] emits `PropertiesChanged`
+//! whenever the now-playing state changes.
+//!
+//! This module is only compiled on Linux (`#![cfg(target_os = "linux")]`); its
+//! call sites in `main.rs` and `sidecar.rs` are `#[cfg(target_os = "linux")]`
+//! gated accordingly.
+
+#![cfg(target_os = "linux")]
+
+use std::collections::HashMap;
+
+use tauri::{AppHandle, Manager, Runtime};
+use zbus::zvariant::{ObjectPath, Value};
+use zbus::{interface, Connection};
+
+use crate::sidecar::SidecarManager;
+use crate::state::SharedState;
+use crate::types::{PlaybackState, SidecarCommand};
+
+const OBJECT_PATH: &str = "/org/mpris/MediaPlayer2";
+const BUS_NAME: &str = "org.mpris.MediaPlayer2.macaroon";
+
+/// Wrapper so the zbus [`Connection`] can live in Tauri's managed state.
+pub struct MprisConnection(pub Connection);
+
+/// The root `org.mpris.MediaPlayer2` interface.
+struct MediaPlayer2;
+
+#[interface(name = "org.mpris.MediaPlayer2")]
+impl MediaPlayer2 {
+    async fn raise(&self) {}
+
+    async fn quit(&self) {}
+
+    #[zbus(property)]
+    fn can_quit(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn can_raise(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn has_track_list(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn identity(&self) -> &str {
+        "Now Playing"
+    }
+
+    #[zbus(property)]
+    fn supported_uri_schemes(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    #[zbus(property)]
+    fn supported_mime_types(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// The `org.mpris.MediaPlayer2.Player` interface mirroring the active zone.
+struct MprisPlayer<R: Runtime> {
+    app: AppHandle<R>,
+    state: SharedState,
+}
+
+impl<R: Runtime> MprisPlayer<R> {
+    /// Send a transport command for the current active zone, if any.
+    async fn command_active_zone(&self, make: impl FnOnce(String) -> SidecarCommand) {
+        let zone_id = {
+            let state_guard = self.state.read().await;
+            state_guard.active_zone_id.clone()
+        };
+
+        let Some(zone_id) = zone_id else {
+            log::warn!("MPRIS command ignored: no active zone");
+            return;
+        };
+
+        if let Some(sidecar) = self.app.try_state::<SidecarManager>() {
+            if let Err(e) = sidecar.send_command(make(zone_id)) {
+                log::error!("MPRIS failed to send sidecar command: {}", e);
+            }
+        }
+    }
+
+    /// The active zone's current playback state, if a track is loaded.
+    async fn current_playback_state(&self) -> Option<PlaybackState> {
+        let state_guard = self.state.read().await;
+        state_guard.current_track.as_ref().map(|t| t.state.clone())
+    }
+}
+
+#[interface(name = "org.mpris.MediaPlayer2.Player")]
+impl<R: Runtime> MprisPlayer<R> {
+    async fn play(&self) {
+        // Play must not pause an already-playing zone: only toggle when not
+        // already playing. `PlayPause` is the sidecar's only transport toggle.
+        match self.current_playback_state().await {
+            Some(PlaybackState::Playing) | Some(PlaybackState::Loading) => {}
+            _ => {
+                self.command_active_zone(|zone_id| SidecarCommand::PlayPause { zone_id })
+                    .await;
+            }
+        }
+    }
+
+    async fn pause(&self) {
+        // Pause must not start a stopped/paused zone: only toggle when playing.
+        match self.current_playback_state().await {
+            Some(PlaybackState::Playing) | Some(PlaybackState::Loading) => {
+                self.command_active_zone(|zone_id| SidecarCommand::PlayPause { zone_id })
+                    .await;
+            }
+            _ => {}
+        }
+    }
+
+    async fn play_pause(&self) {
+        self.command_active_zone(|zone_id| SidecarCommand::PlayPause { zone_id })
+            .await;
+    }
+
+    async fn next(&self) {
+        self.command_active_zone(|zone_id| SidecarCommand::Next { zone_id })
+            .await;
+    }
+
+    async fn previous(&self) {
+        self.command_active_zone(|zone_id| SidecarCommand::Previous { zone_id })
+            .await;
+    }
+
+    async fn stop(&self) {
+        self.command_active_zone(|zone_id| SidecarCommand::Stop { zone_id })
+            .await;
+    }
+
+    #[zbus(property)]
+    async fn playback_status(&self) -> String {
+        let state_guard = self.state.read().await;
+        let status = match state_guard.current_track.as_ref().map(|t| &t.state) {
+            // MPRIS has no Loading state; treat it as Playing.
+            Some(PlaybackState::Playing) | Some(PlaybackState::Loading) => "Playing",
+            Some(PlaybackState::Paused) => "Paused",
+            Some(PlaybackState::Stopped) | None => "Stopped",
+        };
+        status.to_string()
+    }
+
+    #[zbus(property)]
+    async fn metadata(&self) -> HashMap<String, Value<'static>> {
+        let state_guard = self.state.read().await;
+        let mut map: HashMap<String, Value<'static>> = HashMap::new();
+
+        // A valid track object path is required by spec.
+        map.insert(
+            "mpris:trackid".to_string(),
+            Value::from(ObjectPath::try_from("/org/mpris/MediaPlayer2/macaroon/track").unwrap()),
+        );
+
+        if let Some(track) = &state_guard.current_track {
+            map.insert("xesam:title".to_string(), Value::from(track.title.clone()));
+            map.insert(
+                "xesam:artist".to_string(),
+                Value::from(vec![track.artist.clone()]),
+            );
+            map.insert("xesam:album".to_string(), Value::from(track.album.clone()));
+            if let Some(artwork) = &track.artwork {
+                // `artwork` is already a base64 data URL.
+                map.insert("mpris:artUrl".to_string(), Value::from(artwork.clone()));
+            }
+        }
+
+        map
+    }
+
+    #[zbus(property)]
+    fn can_go_next(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_go_previous(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_play(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_pause(&self) -> bool {
+        true
+    }
+
+    #[zbus(property)]
+    fn can_control(&self) -> bool {
+        true
+    }
+}
+
+/// Start the MPRIS server and store the connection in managed state.
+///
+/// Best-effort: logs and returns on failure (e.g. no session bus) so the rest
+/// of the app keeps running.
+pub async fn start<R: Runtime>(app: AppHandle<R>, state: SharedState) {
+    match serve(app.clone(), state).await {
+        Ok(connection) => {
+            app.manage(MprisConnection(connection));
+            log::info!("MPRIS interface registered as {}", BUS_NAME);
+        }
+        Err(e) => {
+            log::warn!("Failed to start MPRIS interface: {}", e);
+        }
+    }
+}
+
+async fn serve<R: Runtime>(app: AppHandle<R>, state: SharedState) -> zbus::Result<Connection> {
+    let player = MprisPlayer {
+        app: app.clone(),
+        state,
+    };
+
+    let connection = zbus::connection::Builder::session()?
+        .name(BUS_NAME)?
+        .serve_at(OBJECT_PATH, MediaPlayer2)?
+        .serve_at(OBJECT_PATH, player)?
+        .build()
+        .await?;
+
+    Ok(connection)
+}
+
+/// Emit `PropertiesChanged` for the Player interface after a now-playing or
+/// zone change. No-op when MPRIS isn't running.
+pub fn notify_changed<R: Runtime>(app: &AppHandle<R>) {
+    let Some(conn) = app.try_state::<MprisConnection>() else {
+        return;
+    };
+    let connection = conn.0.clone();
+    let app = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let iface_ref = match connection
+            .object_server()
+            .interface::<_, MprisPlayer<R>>(OBJECT_PATH)
+            .await
+        {
+            Ok(iface_ref) => iface_ref,
+            Err(e) => {
+                log::debug!("MPRIS interface not available for notify: {}", e);
+                let _ = &app;
+                return;
+            }
+        };
+
+        let iface = iface_ref.get().await;
+        let ctx = iface_ref.signal_context();
+        if let Err(e) = iface.playback_status_changed(ctx).await {
+            log::debug!("MPRIS playback_status_changed failed: {}", e);
+        }
+        if let Err(e) = iface.metadata_changed(ctx).await {
+            log::debug!("MPRIS metadata_changed failed: {}", e);
+        }
+    });
+}
@@ -0,0 +1,168 @@
+//! Pluggable media-source providers.
+//!
+//! The Roon-style sidecar was historically the only source of zones and track
+//! info. A [`MediaProvider`] abstracts that relationship so multiple backends
+//! (Roon, the platform "now playing" service, Spotify, …) can run concurrently
+//! and merge their zones into [`AppState::all_zones`].
+//!
+//! Every provider owns a stable `id_prefix`; all zone ids it publishes are
+//! namespaced as `"{prefix}::{raw_zone_id}"`. That lets `handle_menu_event`
+//! route a selection or transport command back to the owning provider via
+//! [`ProviderRegistry::route`], which strips the prefix before handing the raw
+//! id to the backend.
+
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::state::SharedState;
+use crate::types::SidecarCommand;
+
+/// Separator between a provider prefix and a backend-native zone id.
+pub const PREFIX_SEP: &str = "::";
+
+/// A concurrent source of zones and track updates.
+///
+/// Implementors spawn their own polling loop or event stream in [`start`] and
+/// push [`Zone`](crate::types::Zone) / track updates into the shared state.
+/// Control commands flow back through [`send_command`].
+pub trait MediaProvider: Send + Sync {
+    /// Stable, unique prefix for every zone id this provider publishes.
+    fn id_prefix(&self) -> &str;
+
+    /// Begin producing updates into the shared state. Must not block; spawn a
+    /// task/thread for any long-running poll loop.
+    fn start(&self, state: SharedState);
+
+    /// Forward a control command to this provider's backend. The `zone_id`
+    /// inside `cmd` has already had the provider prefix stripped.
+    fn send_command(&self, cmd: SidecarCommand) -> Result<()>;
+}
+
+/// Namespace a raw zone id with a provider prefix.
+pub fn namespaced(prefix: &str, raw_zone_id: &str) -> String {
+    format!("{}{}{}", prefix, PREFIX_SEP, raw_zone_id)
+}
+
+/// Holds every registered provider and routes prefixed zone ids back to them.
+#[derive(Default, Clone)]
+pub struct ProviderRegistry {
+    providers: Vec<Arc<dyn MediaProvider>>,
+}
+
+impl ProviderRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a provider. Call before [`start_all`].
+    pub fn register(&mut self, provider: Arc<dyn MediaProvider>) {
+        log::info!("Registering media provider: {}", provider.id_prefix());
+        self.providers.push(provider);
+    }
+
+    /// Start every registered provider's polling loop/event stream.
+    pub fn start_all(&self, state: SharedState) {
+        for provider in &self.providers {
+            provider.start(state.clone());
+        }
+    }
+
+    /// Resolve the provider that owns `zone_id` (by its prefix), returning the
+    /// provider and the backend-native zone id with the prefix removed.
+    pub fn route<'a>(&'a self, zone_id: &str) -> Option<(&'a Arc<dyn MediaProvider>, String)> {
+        let (prefix, raw) = zone_id.split_once(PREFIX_SEP)?;
+        self.providers
+            .iter()
+            .find(|p| p.id_prefix() == prefix)
+            .map(|p| (p, raw.to_string()))
+    }
+}
+
+/// A provider for the host OS "now playing" service.
+///
+/// `start` merges a single `platform::system` zone ("System Media", in the
+/// `Stopped` state) into the shared state once, so the rest of the app treats
+/// it like any other zone. It does not read the OS media session: no track or
+/// playback-state updates are produced, and `send_command` only logs the
+/// command.
+pub struct PlatformMediaProvider {
+    prefix: &'static str,
+}
+
+impl PlatformMediaProvider {
+    pub fn new() -> Self {
+        Self { prefix: "platform" }
+    }
+}
+
+impl Default for PlatformMediaProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MediaProvider for PlatformMediaProvider {
+    fn id_prefix(&self) -> &str {
+        self.prefix
+    }
+
+    fn start(&self, state: SharedState) {
+        let prefix = self.prefix;
+        tauri::async_runtime::spawn(async move {
+            log::info!("Platform media provider '{}' started", prefix);
+
+            // Publish this backend's zone into the shared state so it merges
+            // into `all_zones` alongside every other provider. The id is
+            // namespaced with the provider prefix so `handle_menu_event` can
+            // route selections back here.
+            let zone_id = namespaced(prefix, "system");
+
+            let mut state_guard = state.write().await;
+            if !state_guard.all_zones.iter().any(|z| z.zone_id == zone_id) {
+                state_guard.all_zones.push(crate::types::Zone {
+                    zone_id,
+                    display_name: "System Media".to_string(),
+                    state: crate::types::PlaybackState::Stopped,
+                    now_playing: None,
+                    state_changed_at: std::time::Instant::now(),
+                });
+            }
+        });
+    }
+
+    fn send_command(&self, cmd: SidecarCommand) -> Result<()> {
+        log::info!("Platform media provider command: {:?}", cmd);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn namespaced_prefixes_raw_id() {
+        assert_eq!(namespaced("platform", "system"), "platform::system");
+    }
+
+    #[test]
+    fn route_strips_prefix_for_owning_provider() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Arc::new(PlatformMediaProvider::new()));
+
+        let zone_id = namespaced("platform", "system");
+        let (provider, raw) = registry.route(&zone_id).expect("platform zone should route");
+        assert_eq!(provider.id_prefix(), "platform");
+        assert_eq!(raw, "system");
+    }
+
+    #[test]
+    fn route_returns_none_for_unknown_prefix() {
+        let mut registry = ProviderRegistry::new();
+        registry.register(Arc::new(PlatformMediaProvider::new()));
+
+        // Unprefixed (Roon) zone ids and unknown prefixes don't route.
+        assert!(registry.route("roon-zone-1").is_none());
+        assert!(registry.route("spotify::abc").is_none());
+    }
+}
@@ -1,28 +1,66 @@
 use anyhow::{Context, Result};
-use std::io::{BufRead, BufReader};
-use std::process::{Child, Command, Stdio};
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Manager, Runtime};
 
 use crate::state::SharedState;
 use crate::tray::TrayManager;
-use crate::types::{ConnectionStatus, NowPlayingData, SidecarMessage};
+use crate::types::{ConnectionStatus, NowPlayingData, SidecarCommand, SidecarMessage, Zone};
+
+/// No message within this window (even with a live PID) means the sidecar is
+/// stalled and should be restarted.
+const STALL_TIMEOUT: Duration = Duration::from_secs(30);
+/// Backoff floor and ceiling for restart attempts.
+const BACKOFF_FLOOR: Duration = Duration::from_millis(500);
+const BACKOFF_CAP: Duration = Duration::from_secs(30);
+/// Once the sidecar has stayed healthy this long, the backoff resets to floor.
+const HEALTHY_RESET: Duration = Duration::from_secs(60);
+/// Fast-crash guard: more than this many restarts inside the window surfaces a
+/// hard error instead of looping forever.
+const RESTART_WINDOW: Duration = Duration::from_secs(60);
+const MAX_RESTARTS_IN_WINDOW: usize = 5;
 
 /// Manages the Node.js sidecar process
 #[derive(Clone)]
 pub struct SidecarManager {
     child: Arc<Mutex<Option<Child>>>,
+    stdin: Arc<Mutex<Option<ChildStdin>>>,
+    /// Updated by `read_stdout` on every successfully parsed message; the
+    /// supervisor uses it as a heartbeat to detect a stalled process.
+    last_message_at: Arc<Mutex<Instant>>,
+    /// Ensures only one supervisor thread runs across restarts.
+    supervisor_started: Arc<AtomicBool>,
+    /// Set when a `Fatal` message arrives; the supervisor stops restarting.
+    fatal: Arc<AtomicBool>,
 }
 
 impl SidecarManager {
     pub fn new() -> Self {
         Self {
             child: Arc::new(Mutex::new(None)),
+            stdin: Arc::new(Mutex::new(None)),
+            last_message_at: Arc::new(Mutex::new(Instant::now())),
+            supervisor_started: Arc::new(AtomicBool::new(false)),
+            fatal: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    /// Whether the sidecar has hit an unrecoverable condition and should not be
+    /// auto-restarted.
+    pub fn is_fatal(&self) -> bool {
+        self.fatal.load(Ordering::SeqCst)
+    }
+
+    /// Mark the sidecar as unrecoverable; the supervisor will stop restarting.
+    pub fn mark_fatal(&self) {
+        self.fatal.store(true, Ordering::SeqCst);
+    }
+
     /// Spawn the sidecar process and start reading its output
     pub fn spawn<R: Runtime>(
         &mut self,
@@ -60,6 +98,7 @@ impl SidecarManager {
             // Check for ROON_HOST environment variable for manual connection
             let mut cmd = Command::new("node");
             cmd.arg(&script_path)
+                .stdin(Stdio::piped())
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped());
 
@@ -94,7 +133,8 @@ impl SidecarManager {
             }
 
             let mut cmd = Command::new(sidecar_path);
-            cmd.stdout(Stdio::piped())
+            cmd.stdin(Stdio::piped())
+                .stdout(Stdio::piped())
                 .stderr(Stdio::piped());
 
             // Pass through ROON_HOST and ROON_PORT if set
@@ -112,7 +152,12 @@ impl SidecarManager {
 
         log::info!("Sidecar process spawned with PID: {}", child.id());
 
-        // Get stdout and stderr
+        // Get stdin, stdout and stderr
+        let stdin = child
+            .stdin
+            .take()
+            .context("Failed to capture sidecar stdin")?;
+
         let stdout = child
             .stdout
             .take()
@@ -123,14 +168,19 @@ impl SidecarManager {
             .take()
             .context("Failed to capture sidecar stderr")?;
 
-        // Store the child process
+        // Store the child process and its stdin for the command channel
         *self.child.lock().unwrap() = Some(child);
+        *self.stdin.lock().unwrap() = Some(stdin);
+
+        // Reset the heartbeat so a fresh process isn't immediately flagged stale.
+        *self.last_message_at.lock().unwrap() = Instant::now();
 
         // Spawn thread to read stdout (JSON messages)
         let app_handle = app.clone();
         let state_clone = state.clone();
+        let heartbeat = self.last_message_at.clone();
         thread::spawn(move || {
-            Self::read_stdout(stdout, app_handle, state_clone);
+            Self::read_stdout(stdout, app_handle, state_clone, heartbeat);
         });
 
         // Spawn thread to read stderr (debug logs)
@@ -138,14 +188,94 @@ impl SidecarManager {
             Self::read_stderr(stderr);
         });
 
+        // Start the supervisor once; it owns restarts from here on.
+        if !self.supervisor_started.swap(true, Ordering::SeqCst) {
+            self.spawn_supervisor(app, state);
+        }
+
         Ok(())
     }
 
+    /// Spawn the monitoring thread that watches for death/stall and restarts the
+    /// sidecar with exponential backoff.
+    fn spawn_supervisor<R: Runtime>(&self, app: &AppHandle<R>, state: SharedState) {
+        let mut manager = self.clone();
+        let app = app.clone();
+
+        thread::spawn(move || {
+            let mut backoff = BACKOFF_FLOOR;
+            let mut last_healthy = Instant::now();
+            let mut restarts: VecDeque<Instant> = VecDeque::new();
+
+            loop {
+                thread::sleep(Duration::from_secs(1));
+
+                // A fatal condition stops the process for good; leave the
+                // `ConnectionStatus::Error` in place for the UI to render.
+                if manager.is_fatal() {
+                    log::error!("Sidecar in fatal state; supervisor stopping (no restart)");
+                    let _ = manager.stop();
+                    break;
+                }
+
+                let alive = manager.is_running();
+                let stalled = manager.last_message_at.lock().unwrap().elapsed() > STALL_TIMEOUT;
+
+                if alive && !stalled {
+                    // Healthy: reset backoff once we've been up long enough.
+                    if last_healthy.elapsed() > HEALTHY_RESET {
+                        backoff = BACKOFF_FLOOR;
+                    }
+                    continue;
+                }
+
+                log::warn!(
+                    "Sidecar unhealthy (alive={}, stalled={}), restarting...",
+                    alive,
+                    stalled
+                );
+
+                set_connection_status(&state, ConnectionStatus::Disconnected);
+                let _ = manager.stop();
+
+                // Fast-crash guard: prune the sliding window and bail if we've
+                // restarted too often.
+                prune_restart_window(&mut restarts, Instant::now());
+                if restarts.len() >= MAX_RESTARTS_IN_WINDOW {
+                    log::error!("Sidecar restarted too many times; giving up");
+                    set_connection_status(
+                        &state,
+                        ConnectionStatus::Error(
+                            "Sidecar keeps crashing. Check the Roon connection.".to_string(),
+                        ),
+                    );
+                    break;
+                }
+
+                thread::sleep(backoff);
+                restarts.push_back(Instant::now());
+
+                #[cfg(feature = "metrics")]
+                crate::metrics::record_restart();
+
+                // Restart through the supervisor's own handle: a temporary clone
+                // would run `Drop` (and so `stop()`) on the new process.
+                if let Err(e) = manager.spawn(&app, state.clone()) {
+                    log::error!("Failed to restart sidecar: {}", e);
+                }
+
+                backoff = next_backoff(backoff);
+                last_healthy = Instant::now();
+            }
+        });
+    }
+
     /// Read stdout from the sidecar (JSON messages)
     fn read_stdout<R: Runtime>(
         stdout: std::process::ChildStdout,
         app: AppHandle<R>,
         state: SharedState,
+        last_message_at: Arc<Mutex<Instant>>,
     ) {
         let reader = BufReader::new(stdout);
 
@@ -161,12 +291,23 @@ impl SidecarManager {
                     // Parse JSON message
                     match serde_json::from_str::<SidecarMessage>(&line) {
                         Ok(message) => {
+                            // Heartbeat: a parsed message means the sidecar is alive.
+                            *last_message_at.lock().unwrap() = Instant::now();
+
                             if let Err(e) = Self::handle_message(message, &app, &state) {
                                 log::error!("Error handling sidecar message: {}", e);
                             }
                         }
                         Err(e) => {
+                            #[cfg(feature = "metrics")]
+                            crate::metrics::record_parse_failure();
+
                             log::error!("Failed to parse sidecar message: {} - {}", e, line);
+
+                            // Keep the raw line for diagnostics rather than
+                            // letting it vanish into the log.
+                            let mut state_guard = state.blocking_write();
+                            state_guard.record_parse_failure(line);
                         }
                     }
                 }
@@ -207,6 +348,9 @@ impl SidecarManager {
         app: &AppHandle<R>,
         state: &SharedState,
     ) -> Result<()> {
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_message(&message);
+
         match message {
             SidecarMessage::NowPlaying {
                 title,
@@ -238,6 +382,65 @@ impl SidecarManager {
 
                 // Update tray icon
                 TrayManager::update_icon(app, state.clone())?;
+
+                // Steady-state playback update: mutate the existing zone items
+                // in place, only falling back to a full rebuild if the zone set
+                // changed.
+                {
+                    let app_clone = app.clone();
+                    let state_for_menu = state.clone();
+                    tauri::async_runtime::spawn(async move {
+                        if let Err(e) = TrayManager::sync_menu(&app_clone, &state_for_menu).await {
+                            log::error!("Failed to sync menu after now-playing update: {}", e);
+                        }
+                    });
+                }
+
+                // Reflect the new track over MPRIS on Linux.
+                #[cfg(target_os = "linux")]
+                crate::mpris::notify_changed(app);
+            }
+            SidecarMessage::ZoneList { zones } => {
+                log::info!("Received zone list: {} zones", zones.len());
+
+                let app_clone = app.clone();
+                let state_clone = state.clone();
+                tauri::async_runtime::spawn(async move {
+                    {
+                        let mut state_guard = state_clone.write().await;
+
+                        // Keep zones contributed by other providers (their ids
+                        // are prefix-namespaced) and replace the Roon-native
+                        // (unprefixed) zones with this fresh snapshot.
+                        state_guard
+                            .all_zones
+                            .retain(|z| z.zone_id.contains(crate::providers::PREFIX_SEP));
+
+                        for info in zones {
+                            let now_playing = info.now_playing.map(|np| NowPlayingData {
+                                title: np.title,
+                                artist: np.artist,
+                                album: np.album,
+                                state: info.state.clone(),
+                                artwork: np.artwork,
+                            });
+
+                            state_guard.all_zones.push(Zone {
+                                zone_id: info.zone_id,
+                                display_name: info.display_name,
+                                state: info.state,
+                                now_playing,
+                                state_changed_at: Instant::now(),
+                            });
+                        }
+                    }
+
+                    // Zone set likely changed: sync_menu decides rebuild vs
+                    // in-place update via the zone-id hash.
+                    if let Err(e) = TrayManager::sync_menu(&app_clone, &state_clone).await {
+                        log::error!("Failed to sync menu after zone list: {}", e);
+                    }
+                });
             }
             SidecarMessage::Status { state: status_str, message } => {
                 log::info!("Sidecar status: {} - {:?}", status_str, message);
@@ -262,6 +465,21 @@ impl SidecarManager {
             SidecarMessage::Error { message } => {
                 log::error!("Sidecar error: {}", message);
 
+                let state_clone = state.clone();
+                tauri::async_runtime::spawn(async move {
+                    let mut state_guard = state_clone.write().await;
+                    state_guard.connection_status = ConnectionStatus::Error(message);
+                });
+            }
+            SidecarMessage::Fatal { message } => {
+                log::error!("Sidecar fatal error (no restart): {}", message);
+
+                // Mark the manager fatal so the supervisor stops restarting,
+                // and surface a clear error for the UI to render with guidance.
+                if let Some(sidecar) = app.try_state::<SidecarManager>() {
+                    sidecar.mark_fatal();
+                }
+
                 let state_clone = state.clone();
                 tauri::async_runtime::spawn(async move {
                     let mut state_guard = state_clone.write().await;
@@ -273,6 +491,31 @@ impl SidecarManager {
         Ok(())
     }
 
+    /// Send a control command to the sidecar as one newline-delimited JSON line.
+    ///
+    /// Returns an error if the sidecar is not running or its stdin has been
+    /// closed, so callers can surface a "can't control playback right now"
+    /// state rather than silently dropping the command.
+    pub fn send_command(&self, cmd: SidecarCommand) -> Result<()> {
+        let mut stdin_guard = self.stdin.lock().unwrap();
+        let stdin = stdin_guard
+            .as_mut()
+            .context("Sidecar stdin is not available (process not running?)")?;
+
+        let line = serde_json::to_string(&cmd).context("Failed to serialize sidecar command")?;
+        log::info!("Sending sidecar command: {}", line);
+
+        stdin
+            .write_all(line.as_bytes())
+            .context("Failed to write command to sidecar stdin")?;
+        stdin
+            .write_all(b"\n")
+            .context("Failed to write newline to sidecar stdin")?;
+        stdin.flush().context("Failed to flush sidecar stdin")?;
+
+        Ok(())
+    }
+
     /// Check if the sidecar is still running
     pub fn is_running(&self) -> bool {
         let mut child_guard = self.child.lock().unwrap();
@@ -295,6 +538,9 @@ impl SidecarManager {
 
     /// Stop the sidecar process
     pub fn stop(&self) -> Result<()> {
+        // Drop stdin first so the sidecar sees EOF and can shut down cleanly.
+        self.stdin.lock().unwrap().take();
+
         let child_option = self.child.lock().unwrap().take();
         if let Some(mut child) = child_option {
             log::info!("Stopping sidecar process with PID {}...", child.id());
@@ -357,6 +603,56 @@ impl SidecarManager {
     }
 }
 
+/// Set the connection status from a synchronous (non-async) context such as the
+/// supervisor thread.
+fn set_connection_status(state: &SharedState, status: ConnectionStatus) {
+    let mut state_guard = state.blocking_write();
+    state_guard.connection_status = status;
+}
+
+/// Double the backoff, clamped to [`BACKOFF_CAP`].
+fn next_backoff(current: Duration) -> Duration {
+    (current * 2).min(BACKOFF_CAP)
+}
+
+/// Drop restart timestamps older than [`RESTART_WINDOW`] relative to `now`.
+fn prune_restart_window(restarts: &mut VecDeque<Instant>, now: Instant) {
+    while restarts
+        .front()
+        .is_some_and(|t| now.duration_since(*t) > RESTART_WINDOW)
+    {
+        restarts.pop_front();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_then_caps() {
+        assert_eq!(next_backoff(BACKOFF_FLOOR), Duration::from_secs(1));
+        assert_eq!(next_backoff(Duration::from_secs(1)), Duration::from_secs(2));
+        // Never exceeds the cap.
+        assert_eq!(next_backoff(Duration::from_secs(20)), BACKOFF_CAP);
+        assert_eq!(next_backoff(BACKOFF_CAP), BACKOFF_CAP);
+    }
+
+    #[test]
+    fn prune_drops_only_entries_outside_window() {
+        let now = Instant::now();
+        let mut restarts = VecDeque::new();
+        // One stale (outside the window) and two recent timestamps.
+        restarts.push_back(now - RESTART_WINDOW - Duration::from_secs(5));
+        restarts.push_back(now - Duration::from_secs(10));
+        restarts.push_back(now - Duration::from_secs(1));
+
+        prune_restart_window(&mut restarts, now);
+
+        assert_eq!(restarts.len(), 2);
+    }
+}
+
 impl Drop for SidecarManager {
     fn drop(&mut self) {
         log::info!("SidecarManager Drop called, cleaning up...");
@@ -3,12 +3,13 @@ use tauri::{
     image::Image,
     menu::{Menu, MenuItem, PredefinedMenuItem, Submenu, CheckMenuItem},
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
-    AppHandle, Manager, Runtime,
+    AppHandle, LogicalPosition, LogicalSize, Manager, Runtime, WebviewUrl, WebviewWindowBuilder,
 };
 
 use crate::compositor::Compositor;
 use crate::state::SharedState;
-use crate::types::{PlaybackState, ZonePreference};
+use crate::tray_state::{TrayEvent, TrayStateMachine};
+use crate::types::{PlaybackState, SidecarCommand, ZonePreference};
 
 pub struct TrayManager {
     compositor: Compositor,
@@ -42,9 +43,15 @@ impl TrayManager {
         let tray = TrayIconBuilder::new()
             .icon(initial_icon)
             .menu(&menu)
+            // Keep the menu on right-click only so a left-click can raise the
+            // now-playing popover instead of dropping the menu.
+            .show_menu_on_left_click(false)
             .on_menu_event(move |app, event| {
                 Self::handle_menu_event(app, event, &state_for_menu);
             })
+            .on_tray_icon_event(|tray, event| {
+                Self::handle_tray_icon_event(tray.app_handle(), event);
+            })
             .build(app)?;
 
         // Store tray in app state for later updates
@@ -68,6 +75,9 @@ impl TrayManager {
         // Build zones submenu
         let zones_submenu = Self::build_zones_submenu(app, &state_guard)?;
 
+        // Transport controls for the active zone
+        let (play_pause, prev, next) = Self::build_transport_items(app)?;
+
         // Create separator
         let separator = PredefinedMenuItem::separator(app)?;
 
@@ -77,6 +87,9 @@ impl TrayManager {
         // Build final menu
         let menu = Menu::with_items(app, &[
             &zones_submenu,
+            &prev,
+            &play_pause,
+            &next,
             &separator,
             &quit_item,
         ])?;
@@ -93,6 +106,9 @@ impl TrayManager {
         // Build zones submenu
         let zones_submenu = Self::build_zones_submenu(app, &state_guard)?;
 
+        // Transport controls for the active zone
+        let (play_pause, prev, next) = Self::build_transport_items(app)?;
+
         // Create separator
         let separator = PredefinedMenuItem::separator(app)?;
 
@@ -102,6 +118,9 @@ impl TrayManager {
         // Build final menu
         let menu = Menu::with_items(app, &[
             &zones_submenu,
+            &prev,
+            &play_pause,
+            &next,
             &separator,
             &quit_item,
         ])?;
@@ -109,13 +128,26 @@ impl TrayManager {
         Ok(menu)
     }
 
+    /// Build the Previous / Play-Pause / Next transport items. They dispatch the
+    /// matching [`SidecarCommand`] for the `active_zone_id` in `handle_menu_event`.
+    fn build_transport_items<R: Runtime>(
+        app: &AppHandle<R>,
+    ) -> Result<(MenuItem<R>, MenuItem<R>, MenuItem<R>)> {
+        let play_pause =
+            MenuItem::with_id(app, "transport_play_pause", "Play / Pause", true, None::<&str>)?;
+        let prev = MenuItem::with_id(app, "transport_previous", "Previous", true, None::<&str>)?;
+        let next = MenuItem::with_id(app, "transport_next", "Next", true, None::<&str>)?;
+        Ok((play_pause, prev, next))
+    }
+
     /// Build the zones submenu
     fn build_zones_submenu<R: Runtime>(
         app: &AppHandle<R>,
         state_guard: &tokio::sync::RwLockReadGuard<crate::types::AppState>,
     ) -> Result<Submenu<R>> {
-        // Create submenu first
-        let submenu = Submenu::new(app, "Select Zone", true)?;
+        // Create submenu first. The stable id lets `update_zone_items` find the
+        // existing submenu handle for in-place mutation.
+        let submenu = Submenu::with_id(app, "zones_submenu", "Select Zone", true)?;
 
         if state_guard.all_zones.is_empty() {
             // No zones available yet
@@ -139,24 +171,7 @@ impl TrayManager {
                 ZonePreference::Auto => false,
             };
 
-            // Check if this zone is currently being displayed
-            let is_showing = state_guard.active_zone_id.as_ref() == Some(&zone.zone_id);
-            let show_indicator = is_showing && state_guard.is_smart_switched;
-
-            // Format state name
-            let state_str = match zone.state {
-                PlaybackState::Playing => "Playing",
-                PlaybackState::Paused => "Paused",
-                PlaybackState::Stopped => "Stopped",
-                PlaybackState::Loading => "Loading",
-            };
-
-            // Format label
-            let label = if show_indicator {
-                format!("{} ({}) ← Showing", zone.display_name, state_str)
-            } else {
-                format!("{} ({})", zone.display_name, state_str)
-            };
+            let label = Self::zone_item_label(zone, state_guard);
 
             // Create check menu item and append to submenu
             let item = CheckMenuItem::with_id(
@@ -171,9 +186,191 @@ impl TrayManager {
             submenu.append(&item)?;
         }
 
+        // Per-zone volume control for whichever zone is currently active. The
+        // preset levels dispatch a `SetVolume` command for `active_zone_id`.
+        let separator = PredefinedMenuItem::separator(app)?;
+        submenu.append(&separator)?;
+
+        let volume_submenu = Submenu::new(app, "Volume", state_guard.active_zone_id.is_some())?;
+        for value in [0, 25, 50, 75, 100] {
+            let item = MenuItem::with_id(
+                app,
+                format!("set_volume::{}", value),
+                format!("{}%", value),
+                true,
+                None::<&str>,
+            )?;
+            volume_submenu.append(&item)?;
+        }
+        submenu.append(&volume_submenu)?;
+
+        // Global smart-switching settings. These feed the `ZonePreference`
+        // built in `handle_menu_event` instead of fixed defaults. The stable id
+        // lets `update_zone_items` refresh its checkmarks in place.
+        let settings_submenu = Submenu::with_id(app, "smart_switching_submenu", "Smart Switching", true)?;
+
+        let toggle = CheckMenuItem::with_id(
+            app,
+            "smart_switching_toggle",
+            "Enabled",
+            true,
+            state_guard.smart_switching_enabled,
+            None::<&str>,
+        )?;
+        settings_submenu.append(&toggle)?;
+        settings_submenu.append(&PredefinedMenuItem::separator(app)?)?;
+
+        for mins in [1u32, 5, 15, 30] {
+            // Radio-style: the selected grace period is checked.
+            let item = CheckMenuItem::with_id(
+                app,
+                format!("grace_period::{}", mins),
+                format!("{} min grace period", mins),
+                true,
+                state_guard.grace_period_mins == mins,
+                None::<&str>,
+            )?;
+            settings_submenu.append(&item)?;
+        }
+        submenu.append(&settings_submenu)?;
+
         Ok(submenu)
     }
 
+    /// Format the label for a single zone check-menu item, including the
+    /// "← Showing" indicator when it is the smart-switched active zone.
+    fn zone_item_label(
+        zone: &crate::types::Zone,
+        state_guard: &tokio::sync::RwLockReadGuard<crate::types::AppState>,
+    ) -> String {
+        let is_showing = state_guard.active_zone_id.as_ref() == Some(&zone.zone_id);
+        let show_indicator = is_showing && state_guard.is_smart_switched;
+
+        let state_str = match zone.state {
+            PlaybackState::Playing => "Playing",
+            PlaybackState::Paused => "Paused",
+            PlaybackState::Stopped => "Stopped",
+            PlaybackState::Loading => "Loading",
+        };
+
+        if show_indicator {
+            format!("{} ({}) ← Showing", zone.display_name, state_str)
+        } else {
+            format!("{} ({})", zone.display_name, state_str)
+        }
+    }
+
+    /// Compute a stable hash of the current zone-id set (order-independent),
+    /// used to decide whether the zone *set* changed (full rebuild) or only
+    /// per-zone labels/state changed (in-place update).
+    fn zone_ids_hash(zones: &[crate::types::Zone]) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut ids: Vec<&str> = zones.iter().map(|z| z.zone_id.as_str()).collect();
+        ids.sort_unstable();
+
+        let mut hasher = DefaultHasher::new();
+        ids.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Sync the tray menu to the current zone state.
+    ///
+    /// When the zone *set* is unchanged, mutate the existing check-menu items
+    /// in place (`set_text`/`set_checked`) to avoid the flicker and thrash of a
+    /// full teardown. Only when a zone is added or removed do we fall back to
+    /// the full [`build_menu_async`] path.
+    pub async fn sync_menu<R: Runtime>(app: &AppHandle<R>, state: &SharedState) -> Result<()> {
+        let current_hash = {
+            let state_guard = state.read().await;
+            Self::zone_ids_hash(&state_guard.all_zones)
+        };
+
+        let previous_hash = {
+            let state_guard = state.read().await;
+            state_guard.zone_ids_hash
+        };
+
+        if previous_hash == Some(current_hash) {
+            // Steady-state update: only labels / checkmarks changed.
+            Self::update_zone_items(app, state).await?;
+        } else {
+            // Zone set changed: rebuild the whole menu.
+            Self::rebuild_menu(app, state).await?;
+            let mut state_guard = state.write().await;
+            state_guard.zone_ids_hash = Some(current_hash);
+        }
+
+        Ok(())
+    }
+
+    /// Mutate the existing zone check-menu items in place, resolving their live
+    /// handles from the tray's current menu rather than rebuilding.
+    pub async fn update_zone_items<R: Runtime>(app: &AppHandle<R>, state: &SharedState) -> Result<()> {
+        let tray = match app.try_state::<tauri::tray::TrayIcon>() {
+            Some(tray) => tray,
+            None => return Ok(()),
+        };
+
+        let menu = match tray.menu() {
+            Some(menu) => menu,
+            None => return Ok(()),
+        };
+
+        let submenu = match menu.get("zones_submenu").and_then(|kind| kind.as_submenu().cloned()) {
+            Some(submenu) => submenu,
+            None => {
+                // The current menu has no zones submenu; nothing to update.
+                return Ok(());
+            }
+        };
+
+        let state_guard = state.read().await;
+        for zone in &state_guard.all_zones {
+            let Some(kind) = submenu.get(&zone.zone_id) else {
+                continue;
+            };
+            let Some(item) = kind.as_check_menuitem() else {
+                continue;
+            };
+
+            let is_preferred = match &state_guard.zone_preference {
+                ZonePreference::Selected { zone_id, .. } => zone_id == &zone.zone_id,
+                ZonePreference::Auto => false,
+            };
+
+            item.set_text(Self::zone_item_label(zone, &state_guard))?;
+            item.set_checked(is_preferred)?;
+        }
+
+        // Refresh the smart-switching settings checkmarks too: the enabled
+        // toggle and the radio-style grace-period items. Without this the menu
+        // would show no change after the user toggles a setting.
+        if let Some(settings) = submenu
+            .get("smart_switching_submenu")
+            .and_then(|kind| kind.as_submenu().cloned())
+        {
+            if let Some(toggle) = settings
+                .get("smart_switching_toggle")
+                .and_then(|kind| kind.as_check_menuitem().cloned())
+            {
+                toggle.set_checked(state_guard.smart_switching_enabled)?;
+            }
+
+            for mins in [1u32, 5, 15, 30] {
+                if let Some(item) = settings
+                    .get(&format!("grace_period::{}", mins))
+                    .and_then(|kind| kind.as_check_menuitem().cloned())
+                {
+                    item.set_checked(state_guard.grace_period_mins == mins)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Handle menu events
     fn handle_menu_event<R: Runtime>(
         app: &AppHandle<R>,
@@ -189,6 +386,28 @@ impl TrayManager {
             "no_zones" => {
                 // Disabled item, do nothing
             }
+            "transport_play_pause" | "transport_next" | "transport_previous" => {
+                Self::dispatch_transport(app, state, menu_id);
+            }
+            id if id.starts_with("set_volume::") => {
+                if let Some(value) = id
+                    .strip_prefix("set_volume::")
+                    .and_then(|v| v.parse::<i32>().ok())
+                {
+                    Self::dispatch_set_volume(app, state, value);
+                }
+            }
+            "smart_switching_toggle" => {
+                Self::update_smart_switching_setting(app, state, None);
+            }
+            id if id.starts_with("grace_period::") => {
+                if let Some(mins) = id
+                    .strip_prefix("grace_period::")
+                    .and_then(|v| v.parse::<u32>().ok())
+                {
+                    Self::update_smart_switching_setting(app, state, Some(mins));
+                }
+            }
             zone_id => {
                 // This is a zone selection
                 log::info!("Zone selected: {}", zone_id);
@@ -203,8 +422,9 @@ impl TrayManager {
                         let mut state_guard = state.write().await;
                         state_guard.zone_preference = ZonePreference::Selected {
                             zone_id: zone_id.clone(),
-                            smart_switching: true,  // Default enabled
-                            grace_period_mins: 5,   // Default 5 minutes
+                            // Honour the user's persisted smart-switching settings.
+                            smart_switching: state_guard.smart_switching_enabled,
+                            grace_period_mins: state_guard.grace_period_mins,
                         };
 
                         // Reset smart-switch state since user explicitly selected a zone
@@ -213,12 +433,17 @@ impl TrayManager {
 
                         log::info!("Zone preference updated to: {}", zone_id);
 
-                        // Drop the lock before calling rebuild_menu
+                        // Drop the lock before syncing the menu
                         drop(state_guard);
 
-                        // Rebuild menu to show checkmark on selected zone
-                        if let Err(e) = Self::rebuild_menu(&app, &state).await {
-                            log::error!("Failed to rebuild menu: {}", e);
+                        // Tell the backend the active zone changed so it can
+                        // focus transport/now-playing on it.
+                        Self::dispatch_select_zone(&app, &zone_id);
+
+                        // Sync the menu to show the checkmark on the selected
+                        // zone (in place when the zone set is unchanged).
+                        if let Err(e) = Self::sync_menu(&app, &state).await {
+                            log::error!("Failed to sync menu: {}", e);
                         }
 
                         // Update last rebuild time
@@ -236,6 +461,241 @@ impl TrayManager {
         }
     }
 
+    /// Persist a smart-switching setting change. `grace_mins` of `None` toggles
+    /// the enabled flag; `Some(mins)` selects a grace period. The change is
+    /// re-applied to the current `ZonePreference::Selected` so the
+    /// `is_smart_switched`/`preferred_zone_stopped_at` logic honours it.
+    fn update_smart_switching_setting<R: Runtime>(
+        app: &AppHandle<R>,
+        state: &SharedState,
+        grace_mins: Option<u32>,
+    ) {
+        let state = state.clone();
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            {
+                let mut state_guard = state.write().await;
+
+                match grace_mins {
+                    Some(mins) => state_guard.grace_period_mins = mins,
+                    None => state_guard.smart_switching_enabled = !state_guard.smart_switching_enabled,
+                }
+
+                // Re-apply to the active selection so the change takes effect
+                // without requiring the user to re-pick a zone.
+                let (enabled, grace) =
+                    (state_guard.smart_switching_enabled, state_guard.grace_period_mins);
+                if let ZonePreference::Selected {
+                    smart_switching,
+                    grace_period_mins,
+                    ..
+                } = &mut state_guard.zone_preference
+                {
+                    *smart_switching = enabled;
+                    *grace_period_mins = grace;
+                }
+            }
+
+            if let Err(e) = Self::sync_menu(&app, &state).await {
+                log::error!("Failed to sync menu after settings change: {}", e);
+            }
+        });
+    }
+
+    /// Resolve the active zone and forward a transport command, routing the
+    /// menu input through the shared [`TrayStateMachine`].
+    fn dispatch_transport<R: Runtime>(app: &AppHandle<R>, state: &SharedState, menu_id: &str) {
+        let event = match menu_id {
+            "transport_play_pause" => TrayEvent::MenuPlayPause,
+            "transport_next" => TrayEvent::MenuNext,
+            "transport_previous" => TrayEvent::MenuPrevious,
+            _ => return,
+        };
+
+        TrayStateMachine::handle(app, state, event);
+    }
+
+    /// Forward a volume change to the sidecar for the active zone.
+    fn dispatch_set_volume<R: Runtime>(app: &AppHandle<R>, state: &SharedState, value: i32) {
+        Self::dispatch_for_active_zone(app, state, move |zone_id| SidecarCommand::SetVolume {
+            zone_id,
+            value,
+        });
+    }
+
+    /// Advance the preferred zone to the next zone in `all_zones`, wrapping
+    /// around. Used by the `ZoneCycle` tray event.
+    pub fn cycle_zone<R: Runtime>(app: &AppHandle<R>, state: &SharedState) {
+        let state = state.clone();
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            {
+                let mut state_guard = state.write().await;
+                if state_guard.all_zones.is_empty() {
+                    return;
+                }
+
+                let current = match &state_guard.zone_preference {
+                    ZonePreference::Selected { zone_id, .. } => Some(zone_id.clone()),
+                    ZonePreference::Auto => state_guard.active_zone_id.clone(),
+                };
+
+                let index = current
+                    .as_ref()
+                    .and_then(|id| state_guard.all_zones.iter().position(|z| &z.zone_id == id))
+                    .map(|i| (i + 1) % state_guard.all_zones.len())
+                    .unwrap_or(0);
+
+                let next_id = state_guard.all_zones[index].zone_id.clone();
+                let (enabled, grace) =
+                    (state_guard.smart_switching_enabled, state_guard.grace_period_mins);
+                state_guard.zone_preference = ZonePreference::Selected {
+                    zone_id: next_id.clone(),
+                    smart_switching: enabled,
+                    grace_period_mins: grace,
+                };
+                state_guard.is_smart_switched = false;
+                state_guard.preferred_zone_stopped_at = None;
+                log::info!("Cycled preferred zone to: {}", next_id);
+            }
+
+            if let Err(e) = Self::sync_menu(&app, &state).await {
+                log::error!("Failed to sync menu after zone cycle: {}", e);
+            }
+            if let Err(e) = Self::update_icon(&app, state.clone()) {
+                log::error!("Failed to update icon after zone cycle: {}", e);
+            }
+        });
+    }
+
+    /// Send a `SelectZone` command for an explicitly chosen zone, routing to the
+    /// owning provider (prefixed id) or the Roon sidecar (unprefixed id).
+    fn dispatch_select_zone<R: Runtime>(app: &AppHandle<R>, zone_id: &str) {
+        if let Some(registry) = app.try_state::<crate::providers::ProviderRegistry>() {
+            if let Some((provider, raw_zone_id)) = registry.route(zone_id) {
+                if let Err(e) = provider.send_command(SidecarCommand::SelectZone {
+                    zone_id: raw_zone_id,
+                }) {
+                    log::error!("Failed to send SelectZone to provider: {}", e);
+                }
+                return;
+            }
+        }
+
+        if let Some(sidecar) = app.try_state::<crate::sidecar::SidecarManager>() {
+            if let Err(e) = sidecar.send_command(SidecarCommand::SelectZone {
+                zone_id: zone_id.to_string(),
+            }) {
+                log::error!("Failed to send SelectZone to sidecar: {}", e);
+            }
+        }
+    }
+
+    /// Look up `active_zone_id` and hand the resulting [`SidecarCommand`] to the
+    /// owning provider or the managed [`SidecarManager`]. No-ops (with a log)
+    /// when there is no active zone or no backend is available.
+    pub fn dispatch_for_active_zone<R, F>(app: &AppHandle<R>, state: &SharedState, make: F)
+    where
+        R: Runtime,
+        F: FnOnce(String) -> SidecarCommand + Send + 'static,
+    {
+        let state = state.clone();
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let zone_id = {
+                let state_guard = state.read().await;
+                state_guard.active_zone_id.clone()
+            };
+
+            let Some(zone_id) = zone_id else {
+                log::warn!("Ignoring transport command: no active zone");
+                return;
+            };
+
+            // Route to the owning provider when the zone is prefixed; otherwise
+            // fall back to the Roon sidecar for its (unprefixed) zones.
+            if let Some(registry) = app.try_state::<crate::providers::ProviderRegistry>() {
+                if let Some((provider, raw_zone_id)) = registry.route(&zone_id) {
+                    if let Err(e) = provider.send_command(make(raw_zone_id)) {
+                        log::error!("Failed to send command to provider: {}", e);
+                    }
+                    return;
+                }
+            }
+
+            if let Some(sidecar) = app.try_state::<crate::sidecar::SidecarManager>() {
+                if let Err(e) = sidecar.send_command(make(zone_id)) {
+                    log::error!("Failed to send transport command to sidecar: {}", e);
+                }
+            } else {
+                log::error!("SidecarManager not available; cannot send transport command");
+            }
+        });
+    }
+
+    /// Handle raw tray icon events. A left-click (button released) toggles the
+    /// borderless now-playing popover anchored at the icon's reported position;
+    /// the right-click menu is left to Tauri's own handling.
+    fn handle_tray_icon_event<R: Runtime>(app: &AppHandle<R>, event: TrayIconEvent) {
+        if let TrayIconEvent::Click {
+            button: MouseButton::Left,
+            button_state: MouseButtonState::Up,
+            rect,
+            ..
+        } = event
+        {
+            // Anchor the popover just below the icon, centred on it.
+            let position = rect.position;
+            if let Err(e) = Self::toggle_popover(app, position.x, position.y) {
+                log::error!("Failed to toggle now-playing popover: {}", e);
+            }
+        }
+    }
+
+    /// Show (or hide, if already open) the borderless now-playing popover.
+    fn toggle_popover<R: Runtime>(app: &AppHandle<R>, anchor_x: f64, anchor_y: f64) -> Result<()> {
+        const POPOVER_WIDTH: f64 = 320.0;
+        const POPOVER_HEIGHT: f64 = 180.0;
+
+        if let Some(existing) = app.get_webview_window("popover") {
+            // Second click closes it for a native menu-bar feel.
+            existing.close()?;
+            return Ok(());
+        }
+
+        // Centre the popover horizontally on the icon and drop it just below
+        // the menu bar.
+        let x = anchor_x - POPOVER_WIDTH / 2.0;
+        let y = anchor_y;
+
+        let window = WebviewWindowBuilder::new(app, "popover", WebviewUrl::App("popover.html".into()))
+            .title("Now Playing")
+            .inner_size(POPOVER_WIDTH, POPOVER_HEIGHT)
+            .position(x.max(0.0), y)
+            .decorations(false)
+            .resizable(false)
+            .always_on_top(true)
+            .skip_taskbar(true)
+            .focused(true)
+            .build()
+            .context("Failed to build now-playing popover window")?;
+
+        // Dismiss the popover as soon as it loses focus, matching the menu-bar
+        // idiom of a transient panel.
+        let popover = window.clone();
+        window.on_window_event(move |event| {
+            if let tauri::WindowEvent::Focused(false) = event {
+                let _ = popover.close();
+            }
+        });
+
+        // Keep the logical size/position exact across HiDPI displays.
+        let _ = window.set_size(LogicalSize::new(POPOVER_WIDTH, POPOVER_HEIGHT));
+        let _ = window.set_position(LogicalPosition::new(x.max(0.0), y));
+
+        Ok(())
+    }
+
     /// Rebuild the tray menu (called when zones change or preference changes)
     pub async fn rebuild_menu<R: Runtime>(app: &AppHandle<R>, state: &SharedState) -> Result<()> {
         log::warn!("╔═══════════════════════════════");
@@ -380,3 +840,39 @@ impl TrayManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PlaybackState, Zone};
+
+    fn zone(id: &str) -> Zone {
+        Zone {
+            zone_id: id.to_string(),
+            display_name: id.to_string(),
+            state: PlaybackState::Stopped,
+            now_playing: None,
+            state_changed_at: std::time::Instant::now(),
+        }
+    }
+
+    #[test]
+    fn zone_ids_hash_is_order_independent() {
+        let a = [zone("z1"), zone("z2"), zone("z3")];
+        let b = [zone("z3"), zone("z1"), zone("z2")];
+        assert_eq!(
+            TrayManager::zone_ids_hash(&a),
+            TrayManager::zone_ids_hash(&b)
+        );
+    }
+
+    #[test]
+    fn zone_ids_hash_changes_when_set_changes() {
+        let a = [zone("z1"), zone("z2")];
+        let b = [zone("z1"), zone("z2"), zone("z3")];
+        assert_ne!(
+            TrayManager::zone_ids_hash(&a),
+            TrayManager::zone_ids_hash(&b)
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! Tray/playback interaction modelled as an explicit state machine.
+//!
+//! Both menu clicks and OS-level global media shortcuts are normalised into a
+//! single [`TrayEvent`] enum, and [`TrayStateMachine`] is the one place that
+//! maps those events onto [`SidecarCommand`]s and zone transitions. Funnelling
+//! every input through one transition table keeps playback control auditable
+//! regardless of where the input came from.
+
+use tauri::{AppHandle, Runtime};
+
+use crate::state::SharedState;
+use crate::tray::TrayManager;
+use crate::types::SidecarCommand;
+
+/// Every input that can drive playback, from either the menu or a hotkey.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayEvent {
+    HotkeyPlayPause,
+    HotkeyNext,
+    HotkeyPrevious,
+    MenuPlayPause,
+    MenuNext,
+    MenuPrevious,
+    /// Advance the preferred/active zone to the next discovered zone.
+    ZoneCycle,
+}
+
+/// The action a [`TrayEvent`] resolves to. Keeping this as a small intermediate
+/// enum makes the transition table below trivially auditable.
+enum TrayAction {
+    /// Send a transport command to whichever backend owns the active zone.
+    Transport(fn(String) -> SidecarCommand),
+    /// Cycle the preferred zone.
+    CycleZone,
+}
+
+/// Stateless dispatcher: the "current state" lives in `AppState`, so the
+/// machine only needs the pure event → action mapping.
+pub struct TrayStateMachine;
+
+impl TrayStateMachine {
+    /// The single transition table mapping a [`TrayEvent`] to its action.
+    fn transition(event: TrayEvent) -> TrayAction {
+        match event {
+            TrayEvent::HotkeyPlayPause | TrayEvent::MenuPlayPause => {
+                TrayAction::Transport(|zone_id| SidecarCommand::PlayPause { zone_id })
+            }
+            TrayEvent::HotkeyNext | TrayEvent::MenuNext => {
+                TrayAction::Transport(|zone_id| SidecarCommand::Next { zone_id })
+            }
+            TrayEvent::HotkeyPrevious | TrayEvent::MenuPrevious => {
+                TrayAction::Transport(|zone_id| SidecarCommand::Previous { zone_id })
+            }
+            TrayEvent::ZoneCycle => TrayAction::CycleZone,
+        }
+    }
+
+    /// Apply a tray event: resolve it through the transition table and execute.
+    pub fn handle<R: Runtime>(app: &AppHandle<R>, state: &SharedState, event: TrayEvent) {
+        log::info!("Tray event: {:?}", event);
+
+        match Self::transition(event) {
+            TrayAction::Transport(make) => {
+                TrayManager::dispatch_for_active_zone(app, state, move |zone_id| make(zone_id));
+            }
+            TrayAction::CycleZone => {
+                TrayManager::cycle_zone(app, state);
+            }
+        }
+    }
+}
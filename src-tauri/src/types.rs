@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::time::Instant;
 
+/// How many recent raw parse failures to retain for diagnostics.
+const PARSE_FAILURE_CAPACITY: usize = 20;
+
 /// Sidecar message types - these match the JSON output from the Node.js sidecar
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -24,6 +28,28 @@ pub enum SidecarMessage {
     Error {
         message: String,
     },
+    /// An unrecoverable condition (e.g. missing extension token, incompatible
+    /// core version). Unlike [`SidecarMessage::Error`], this tears the sidecar
+    /// down without auto-restart.
+    Fatal {
+        message: String,
+    },
+}
+
+/// Control messages sent back to the Node.js sidecar over its stdin.
+///
+/// Serialized the same tagged way as [`SidecarMessage`] so the sidecar can
+/// demultiplex a single newline-delimited JSON stream in both directions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SidecarCommand {
+    PlayPause { zone_id: String },
+    Next { zone_id: String },
+    Previous { zone_id: String },
+    Stop { zone_id: String },
+    SelectZone { zone_id: String },
+    Seek { zone_id: String, seconds: u32 },
+    SetVolume { zone_id: String, value: i32 },
 }
 
 /// Zone information from sidecar
@@ -44,6 +70,24 @@ pub struct NowPlayingInfo {
     pub artwork: Option<String>,
 }
 
+impl From<&Zone> for ZoneInfo {
+    /// Project the internally-tracked [`Zone`] onto the serializable
+    /// [`ZoneInfo`] handed to the frontend and IPC clients.
+    fn from(zone: &Zone) -> Self {
+        ZoneInfo {
+            zone_id: zone.zone_id.clone(),
+            display_name: zone.display_name.clone(),
+            state: zone.state.clone(),
+            now_playing: zone.now_playing.as_ref().map(|np| NowPlayingInfo {
+                title: np.title.clone(),
+                artist: np.artist.clone(),
+                album: np.album.clone(),
+                artwork: np.artwork.clone(),
+            }),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NowPlayingData {
     pub title: String,
@@ -114,6 +158,31 @@ pub struct AppState {
     pub is_smart_switched: bool,
     pub last_menu_rebuild: Option<Instant>,
     pub needs_menu_rebuild: bool, // Force rebuild on next opportunity
+
+    /// Stable hash of the current zone-id set. Used to tell a full menu
+    /// rebuild (zone added/removed) apart from an in-place label/checkmark
+    /// update of the existing items.
+    pub zone_ids_hash: Option<u64>,
+
+    // User-configurable smart-switching settings, applied when building a
+    // `ZonePreference::Selected` rather than using fixed defaults.
+    pub smart_switching_enabled: bool,
+    pub grace_period_mins: u32,
+
+    /// Bounded ring buffer of the most recent raw lines that failed to parse,
+    /// kept for diagnostics instead of vanishing into the log.
+    pub parse_failures: VecDeque<String>,
+}
+
+impl AppState {
+    /// Record a raw line that failed to parse, evicting the oldest entry once
+    /// the buffer is full.
+    pub fn record_parse_failure(&mut self, raw: String) {
+        if self.parse_failures.len() >= PARSE_FAILURE_CAPACITY {
+            self.parse_failures.pop_front();
+        }
+        self.parse_failures.push_back(raw);
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -136,6 +205,33 @@ impl Default for AppState {
             is_smart_switched: false,
             last_menu_rebuild: None,
             needs_menu_rebuild: false,
+            zone_ids_hash: None,
+            smart_switching_enabled: default_smart_switching(),
+            grace_period_mins: default_grace_period(),
+            parse_failures: VecDeque::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_failures_are_bounded_and_keep_most_recent() {
+        let mut state = AppState::default();
+
+        // Push five more than capacity.
+        for i in 0..(PARSE_FAILURE_CAPACITY + 5) {
+            state.record_parse_failure(format!("line {}", i));
         }
+
+        assert_eq!(state.parse_failures.len(), PARSE_FAILURE_CAPACITY);
+        // The oldest entries were evicted; the newest is retained.
+        assert_eq!(
+            state.parse_failures.back().unwrap(),
+            &format!("line {}", PARSE_FAILURE_CAPACITY + 4)
+        );
+        assert_eq!(state.parse_failures.front().unwrap(), "line 5");
     }
 }